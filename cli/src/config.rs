@@ -0,0 +1,105 @@
+use super::error::{Error, Result};
+use serde_derive::Deserialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Default, Deserialize)]
+pub struct Config {
+    #[serde(rename = "parser-directories", default)]
+    parser_directories: Vec<String>,
+}
+
+impl Config {
+    pub fn load(config_dir: &Path) -> Result<Self> {
+        let path = config_dir.join("config.json");
+        match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| Error::Other(format!("Failed to parse {:?}: {}", path, e))),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    // Directories given on the command line take precedence over the ones in the
+    // config file. If neither lists any, parsers are looked up in `~/github`.
+    pub fn parser_directories<'a>(
+        &self,
+        paths: impl Iterator<Item = &'a str>,
+        home_dir: &Path,
+        current_dir: &Path,
+    ) -> Vec<PathBuf> {
+        let mut result = paths
+            .map(|path| expand_path(path, home_dir, current_dir))
+            .collect::<Vec<_>>();
+        if result.is_empty() {
+            result.extend(
+                self.parser_directories
+                    .iter()
+                    .map(|path| expand_path(path, home_dir, current_dir)),
+            );
+        }
+        if result.is_empty() {
+            result.push(home_dir.join("github"));
+        }
+        result
+    }
+}
+
+fn expand_path(path: &str, home_dir: &Path, current_dir: &Path) -> PathBuf {
+    if path == "~" {
+        home_dir.to_owned()
+    } else if path.starts_with("~/") {
+        home_dir.join(&path[2..])
+    } else {
+        current_dir.join(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::fixtures::scratch_dir;
+
+    #[test]
+    fn test_loading_a_malformed_config() {
+        let config_dir = scratch_dir().join("malformed-config");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(config_dir.join("config.json"), "{").unwrap();
+
+        let message = Config::load(&config_dir).err().unwrap().to_string();
+        assert!(message.starts_with(&format!(
+            "Failed to parse {:?}: ",
+            config_dir.join("config.json")
+        )));
+    }
+
+    #[test]
+    fn test_parser_directories() {
+        let home_dir = Path::new("/home/user");
+        let current_dir = Path::new("/work");
+
+        let config = Config::default();
+        assert_eq!(
+            config.parser_directories(None.into_iter(), home_dir, current_dir),
+            vec![PathBuf::from("/home/user/github")]
+        );
+
+        let config: Config = serde_json::from_str(
+            r#"{"parser-directories": ["~/code", "parsers", "/opt/grammars"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.parser_directories(None.into_iter(), home_dir, current_dir),
+            vec![
+                PathBuf::from("/home/user/code"),
+                PathBuf::from("/work/parsers"),
+                PathBuf::from("/opt/grammars"),
+            ]
+        );
+        assert_eq!(
+            config.parser_directories(vec!["~", "src"].into_iter(), home_dir, current_dir),
+            vec![PathBuf::from("/home/user"), PathBuf::from("/work/src")]
+        );
+    }
+}
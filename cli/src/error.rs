@@ -1,44 +1,80 @@
+use std::fmt;
 use std::io;
 
 #[derive(Debug)]
-pub struct Error(pub String);
+pub enum Error {
+    Io(io::Error),
+    WriteFile(String, io::Error),
+    Json(serde_json::Error),
+    Language(String),
+    PropertySheet(String),
+    Generate(String),
+    Parse,
+    TestFailure,
+    Other(String),
+}
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 impl Error {
     pub fn grammar(message: &str) -> Self {
-        Error(format!("Grammar error: {}", message))
+        Error::Generate(format!("Grammar error: {}", message))
     }
 
     pub fn regex(message: &str) -> Self {
-        Error(format!("Regex error: {}", message))
+        Error::Generate(format!("Regex error: {}", message))
     }
 
     pub fn undefined_symbol(name: &str) -> Self {
-        Error(format!("Undefined symbol `{}`", name))
+        Error::Generate(format!("Undefined symbol `{}`", name))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{}", error),
+            Error::WriteFile(message, error) => write!(f, "{}: {}", message, error),
+            Error::Json(error) => write!(f, "{}", error),
+            Error::Language(message)
+            | Error::PropertySheet(message)
+            | Error::Generate(message)
+            | Error::Other(message) => write!(f, "{}", message),
+            Error::Parse | Error::TestFailure => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) | Error::WriteFile(_, error) => Some(error),
+            Error::Json(error) => Some(error),
+            _ => None,
+        }
     }
 }
 
 impl From<serde_json::Error> for Error {
     fn from(error: serde_json::Error) -> Self {
-        Error(error.to_string())
+        Error::Json(error)
     }
 }
 
 impl From<io::Error> for Error {
     fn from(error: io::Error) -> Self {
-        Error(error.to_string())
+        Error::Io(error)
     }
 }
 
 impl From<rsass::Error> for Error {
     fn from(error: rsass::Error) -> Self {
-        Error(error.to_string())
+        Error::PropertySheet(error.to_string())
     }
 }
 
 impl From<String> for Error {
     fn from(error: String) -> Self {
-        Error(error)
+        Error::Other(error)
     }
 }
@@ -42,6 +42,7 @@ struct ParseTableBuilder<'a> {
     parse_state_queue: VecDeque<ParseStateQueueEntry>,
     parse_table: ParseTable,
     state_ids_to_log: Vec<ParseStateId>,
+    conflict_log: Option<&'a mut Vec<String>>,
 }
 
 impl<'a> ParseTableBuilder<'a> {
@@ -187,14 +188,30 @@ impl<'a> ParseTableBuilder<'a> {
                     let entry = entry.or_insert_with(|| ParseTableEntry::new());
                     if entry.actions.is_empty() {
                         entry.actions.push(action);
-                    } else if action.precedence() > entry.actions[0].precedence() {
+                        continue;
+                    }
+
+                    // REDUCE-REDUCE conflicts between actions with different
+                    // precedences are resolved here, rather than in `handle_conflict`.
+                    let conflict_symbols = entry
+                        .actions
+                        .iter()
+                        .chain(Some(&action))
+                        .filter_map(|action| match action {
+                            ParseAction::Reduce { symbol, .. } => Some(*symbol),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+                    if action.precedence() > entry.actions[0].precedence() {
                         entry.actions.clear();
                         entry.actions.push(action);
                         lookaheads_with_conflicts.remove(&lookahead);
                     } else if action.precedence() == entry.actions[0].precedence() {
                         entry.actions.push(action);
                         lookaheads_with_conflicts.insert(lookahead);
+                        continue;
                     }
+                    self.log_conflict(state_id, lookahead, &conflict_symbols, "precedence");
                 }
             }
         }
@@ -284,6 +301,7 @@ impl<'a> ParseTableBuilder<'a> {
         // precedence, and there can still be SHIFT/REDUCE conflicts.
         let reduce_precedence = entry.actions[0].precedence();
         let mut considered_associativity = false;
+        let mut resolution = "precedence";
         let mut shift_precedence: Option<Range<i32>> = None;
         let mut conflicting_items = HashSet::new();
         for (item, lookaheads) in &item_set.entries {
@@ -317,6 +335,11 @@ impl<'a> ParseTableBuilder<'a> {
             }
         }
 
+        let conflict_symbols = conflicting_items
+            .iter()
+            .map(|item| Symbol::non_terminal(item.variable_index as usize))
+            .collect::<Vec<_>>();
+
         if let ParseAction::Shift { is_repetition, .. } = entry.actions.last_mut().unwrap() {
             let shift_precedence = shift_precedence.unwrap_or(0..0);
 
@@ -333,6 +356,12 @@ impl<'a> ParseTableBuilder<'a> {
                     .all(|item| item.variable_index == conflicting_variable_index)
                 {
                     *is_repetition = true;
+                    self.log_conflict(
+                        state_id,
+                        conflicting_lookahead,
+                        &conflict_symbols,
+                        "repetition",
+                    );
                     return Ok(());
                 }
             }
@@ -375,9 +404,11 @@ impl<'a> ParseTableBuilder<'a> {
                     (true, false, false) => {
                         entry.actions.pop();
                         conflicting_items.retain(|item| item.is_done());
+                        resolution = "left associativity";
                     }
                     (false, false, true) => {
                         entry.actions.drain(0..entry.actions.len() - 1);
+                        resolution = "right associativity";
                     }
                     _ => {}
                 }
@@ -390,6 +421,12 @@ impl<'a> ParseTableBuilder<'a> {
             .get_mut(&conflicting_lookahead)
             .unwrap();
         if entry.actions.len() == 1 {
+            self.log_conflict(
+                state_id,
+                conflicting_lookahead,
+                &conflict_symbols,
+                resolution,
+            );
             return Ok(());
         }
 
@@ -425,9 +462,22 @@ impl<'a> ParseTableBuilder<'a> {
             .expected_conflicts
             .contains(&actual_conflict)
         {
+            self.log_conflict(
+                state_id,
+                conflicting_lookahead,
+                &conflict_symbols,
+                "expected conflict",
+            );
             return Ok(());
         }
 
+        self.log_conflict(
+            state_id,
+            conflicting_lookahead,
+            &conflict_symbols,
+            "unresolved",
+        );
+
         let mut msg = "Unresolved conflict for symbol sequence:\n\n".to_string();
         for symbol in preceding_symbols {
             write!(&mut msg, "  {}", self.symbol_name(symbol)).unwrap();
@@ -597,7 +647,35 @@ impl<'a> ParseTableBuilder<'a> {
         }
         write!(&mut msg, "\n").unwrap();
 
-        Err(Error(msg))
+        Err(Error::Generate(msg))
+    }
+
+    fn log_conflict(
+        &mut self,
+        state_id: ParseStateId,
+        conflicting_lookahead: Symbol,
+        conflict_symbols: &[Symbol],
+        resolution: &str,
+    ) {
+        if self.conflict_log.is_none() {
+            return;
+        }
+        let mut rule_names = conflict_symbols
+            .iter()
+            .map(|symbol| self.symbol_name(symbol))
+            .collect::<Vec<_>>();
+        rule_names.sort_unstable();
+        rule_names.dedup();
+        let line = format!(
+            "state {}, lookahead {}: {} ({})",
+            state_id,
+            self.symbol_name(&conflicting_lookahead),
+            rule_names.join(", "),
+            resolution
+        );
+        if let Some(conflict_log) = self.conflict_log.as_mut() {
+            conflict_log.push(line);
+        }
     }
 
     fn get_auxiliary_node_info(
@@ -719,6 +797,7 @@ pub(crate) fn build_parse_table(
     lexical_grammar: &LexicalGrammar,
     inlines: &InlinedProductionMap,
     state_ids_to_log: Vec<usize>,
+    conflict_log: Option<&mut Vec<String>>,
 ) -> Result<(ParseTable, Vec<TokenSet>)> {
     let item_set_builder = ParseItemSetBuilder::new(syntax_grammar, lexical_grammar, inlines);
     let mut following_tokens = vec![TokenSet::new(); lexical_grammar.variables.len()];
@@ -733,6 +812,7 @@ pub(crate) fn build_parse_table(
         syntax_grammar,
         lexical_grammar,
         state_ids_to_log,
+        conflict_log,
         item_set_builder,
         state_ids_by_item_set: HashMap::new(),
         item_sets_by_state_id: Vec::new(),
@@ -748,3 +828,105 @@ pub(crate) fn build_parse_table(
 
     Ok((table, following_tokens))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::parse_grammar::parse_grammar;
+    use crate::generate::prepare_grammar::prepare_grammar;
+
+    #[test]
+    fn test_logging_conflicts() {
+        let input_grammar = parse_grammar(
+            r#"{
+            "name": "conflicts",
+            "rules": {
+                "program": {
+                    "type": "CHOICE",
+                    "members": [
+                        {"type": "SYMBOL", "name": "expression"},
+                        {"type": "SYMBOL", "name": "a_word"},
+                        {"type": "SYMBOL", "name": "b_word"}
+                    ]
+                },
+                "expression": {
+                    "type": "CHOICE",
+                    "members": [
+                        {"type": "SYMBOL", "name": "sum"},
+                        {"type": "SYMBOL", "name": "product"},
+                        {"type": "SYMBOL", "name": "number"}
+                    ]
+                },
+                "sum": {
+                    "type": "PREC_LEFT",
+                    "value": 1,
+                    "content": {
+                        "type": "SEQ",
+                        "members": [
+                            {"type": "SYMBOL", "name": "expression"},
+                            {"type": "STRING", "value": "+"},
+                            {"type": "SYMBOL", "name": "expression"}
+                        ]
+                    }
+                },
+                "product": {
+                    "type": "PREC_LEFT",
+                    "value": 2,
+                    "content": {
+                        "type": "SEQ",
+                        "members": [
+                            {"type": "SYMBOL", "name": "expression"},
+                            {"type": "STRING", "value": "*"},
+                            {"type": "SYMBOL", "name": "expression"}
+                        ]
+                    }
+                },
+                "a_word": {
+                    "type": "PREC",
+                    "value": 1,
+                    "content": {"type": "SYMBOL", "name": "identifier"}
+                },
+                "b_word": {
+                    "type": "PREC",
+                    "value": 2,
+                    "content": {"type": "SYMBOL", "name": "identifier"}
+                },
+                "number": {"type": "PATTERN", "value": "\\d+"},
+                "identifier": {"type": "PATTERN", "value": "[a-z]+"}
+            }
+        }"#,
+        )
+        .unwrap();
+        let (syntax_grammar, lexical_grammar, inlines, _) =
+            prepare_grammar(&input_grammar).unwrap();
+
+        let mut conflict_log = Vec::new();
+        build_parse_table(
+            &syntax_grammar,
+            &lexical_grammar,
+            &inlines,
+            Vec::new(),
+            Some(&mut conflict_log),
+        )
+        .unwrap();
+
+        // The same conflict can occur in several states, so compare the
+        // distinct lines with their state ids removed.
+        let mut lines = conflict_log
+            .iter()
+            .map(|line| line.splitn(2, ", ").nth(1).unwrap().to_string())
+            .collect::<Vec<_>>();
+        lines.sort_unstable();
+        lines.dedup();
+        assert_eq!(
+            lines,
+            vec![
+                "lookahead '*': product (left associativity)",
+                "lookahead '*': product, sum (precedence)",
+                "lookahead '+': product, sum (precedence)",
+                "lookahead '+': sum (left associativity)",
+                "lookahead EOF: a_word, b_word (precedence)",
+            ]
+        );
+    }
+}
@@ -17,6 +17,7 @@ use crate::generate::grammars::{InlinedProductionMap, LexicalGrammar, SyntaxGram
 use crate::generate::nfa::{CharacterSet, NfaCursor};
 use crate::generate::rules::{AliasMap, Symbol, SymbolType};
 use crate::generate::tables::{LexTable, ParseAction, ParseTable, ParseTableEntry};
+use crate::generate::Profile;
 use log::info;
 
 pub(crate) fn build_tables(
@@ -26,9 +27,25 @@ pub(crate) fn build_tables(
     inlines: &InlinedProductionMap,
     minimize: bool,
     state_ids_to_log: Vec<usize>,
+    log_conflicts: bool,
+    profile: &mut Profile,
 ) -> Result<(ParseTable, LexTable, LexTable, Option<Symbol>)> {
-    let (mut parse_table, following_tokens) =
-        build_parse_table(syntax_grammar, lexical_grammar, inlines, state_ids_to_log)?;
+    let mut conflict_log = Vec::new();
+    let result = build_parse_table(
+        syntax_grammar,
+        lexical_grammar,
+        inlines,
+        state_ids_to_log,
+        if log_conflicts {
+            Some(&mut conflict_log)
+        } else {
+            None
+        },
+    );
+    for line in conflict_log {
+        eprintln!("{}", line);
+    }
+    let (mut parse_table, following_tokens) = result?;
     let token_conflict_map = TokenConflictMap::new(lexical_grammar, following_tokens);
     let coincident_token_index = CoincidentTokenIndex::new(&parse_table, lexical_grammar);
     let keywords = identify_keywords(
@@ -47,6 +64,7 @@ pub(crate) fn build_tables(
         &keywords,
     );
     populate_used_symbols(&mut parse_table, syntax_grammar, lexical_grammar);
+    profile.end_phase("build parse table");
     if minimize {
         minimize_parse_table(
             &mut parse_table,
@@ -56,6 +74,7 @@ pub(crate) fn build_tables(
             &token_conflict_map,
             &keywords,
         );
+        profile.end_phase("minimize parse table");
     }
     let (main_lex_table, keyword_lex_table) = build_lex_table(
         &mut parse_table,
@@ -67,6 +86,7 @@ pub(crate) fn build_tables(
         minimize,
     );
     mark_fragile_tokens(&mut parse_table, lexical_grammar, &token_conflict_map);
+    profile.end_phase("build lex tables");
     Ok((
         parse_table,
         main_lex_table,
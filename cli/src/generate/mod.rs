@@ -10,12 +10,17 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 
+pub use self::output::{Output, OutputMode};
+pub use self::profile::Profile;
+
 mod build_tables;
 mod grammars;
 mod nfa;
 mod npm_files;
+mod output;
 mod parse_grammar;
 mod prepare_grammar;
+mod profile;
 mod render;
 mod rules;
 mod tables;
@@ -32,12 +37,15 @@ pub fn generate_parser_in_directory(
     grammar_path: Option<&str>,
     minimize: bool,
     state_ids_to_log: Vec<usize>,
+    log_conflicts: bool,
+    profile: &mut Profile,
+    output: &mut Output,
 ) -> Result<()> {
     let repo_src_path = repo_path.join("src");
     let repo_header_path = repo_src_path.join("tree_sitter");
 
-    fs::create_dir_all(&repo_src_path)?;
-    fs::create_dir_all(&repo_header_path)?;
+    output.create_dir_all(&repo_src_path)?;
+    output.create_dir_all(&repo_header_path)?;
 
     let grammar_json;
     match grammar_path {
@@ -47,44 +55,63 @@ pub fn generate_parser_in_directory(
         None => {
             let grammar_js_path = grammar_path.map_or(repo_path.join("grammar.js"), |s| s.into());
             grammar_json = load_grammar_file(&grammar_js_path)?;
-            fs::write(&repo_src_path.join("grammar.json"), &grammar_json)?;
+            output.write(&repo_src_path.join("grammar.json"), &grammar_json)?;
         }
     }
+    profile.end_phase("load grammar");
 
-    let (language_name, c_code) =
-        generate_parser_for_grammar_with_opts(&grammar_json, minimize, state_ids_to_log)?;
-    fs::write(&repo_src_path.join("parser.c"), c_code)
-        .map_err(|e| format!("Failed to write parser.c: {}", e))?;
-    fs::write(
-        &repo_header_path.join("parser.h"),
-        tree_sitter::PARSER_HEADER,
-    )
-    .map_err(|e| format!("Failed to write parser.h: {}", e))?;
-    ensure_file(&repo_src_path.join("binding.cc"), || {
+    let (language_name, c_code) = generate_parser_for_grammar_with_opts(
+        &grammar_json,
+        minimize,
+        state_ids_to_log,
+        log_conflicts,
+        profile,
+    )?;
+    output
+        .write(&repo_src_path.join("parser.c"), c_code)
+        .map_err(|e| Error::WriteFile("Failed to write parser.c".to_string(), e))?;
+    output
+        .write(
+            &repo_header_path.join("parser.h"),
+            tree_sitter::PARSER_HEADER,
+        )
+        .map_err(|e| Error::WriteFile("Failed to write parser.h".to_string(), e))?;
+    ensure_file(output, &repo_src_path.join("binding.cc"), || {
         npm_files::binding_cc(&language_name)
     })?;
-    ensure_file(&repo_path.join("binding.gyp"), || {
+    ensure_file(output, &repo_path.join("binding.gyp"), || {
         npm_files::binding_gyp(&language_name)
     })?;
-    ensure_file(&repo_path.join("index.js"), || {
+    ensure_file(output, &repo_path.join("index.js"), || {
         npm_files::index_js(&language_name)
     })?;
+    profile.end_phase("write files");
     Ok(())
 }
 
 pub fn generate_parser_for_grammar(grammar_json: &str) -> Result<(String, String)> {
     let grammar_json = JSON_COMMENT_REGEX.replace_all(grammar_json, "\n");
-    generate_parser_for_grammar_with_opts(&grammar_json, true, Vec::new())
+    generate_parser_for_grammar_with_opts(
+        &grammar_json,
+        true,
+        Vec::new(),
+        false,
+        &mut Profile::new(false),
+    )
 }
 
 fn generate_parser_for_grammar_with_opts(
     grammar_json: &str,
     minimize: bool,
     state_ids_to_log: Vec<usize>,
+    log_conflicts: bool,
+    profile: &mut Profile,
 ) -> Result<(String, String)> {
     let input_grammar = parse_grammar(grammar_json)?;
+    profile.end_phase("parse grammar");
     let (syntax_grammar, lexical_grammar, inlines, simple_aliases) =
         prepare_grammar(&input_grammar)?;
+    profile.end_phase("prepare grammar");
     let (parse_table, main_lex_table, keyword_lex_table, keyword_capture_token) = build_tables(
         &syntax_grammar,
         &lexical_grammar,
@@ -92,6 +119,8 @@ fn generate_parser_for_grammar_with_opts(
         &inlines,
         minimize,
         state_ids_to_log,
+        log_conflicts,
+        profile,
     )?;
     let c_code = render_c_code(
         &input_grammar.name,
@@ -103,14 +132,25 @@ fn generate_parser_for_grammar_with_opts(
         lexical_grammar,
         simple_aliases,
     );
+    profile.end_phase("render C code");
     Ok((input_grammar.name, c_code))
 }
 
+fn ensure_file<T: AsRef<[u8]>>(
+    output: &mut Output,
+    path: &PathBuf,
+    f: impl Fn() -> T,
+) -> Result<()> {
+    output
+        .ensure(path, f)
+        .map_err(|e| Error::WriteFile(format!("Failed to write file {:?}", path), e))
+}
+
 fn load_grammar_file(grammar_path: &Path) -> Result<String> {
     match grammar_path.extension().and_then(|e| e.to_str()) {
         Some("js") => Ok(load_js_grammar_file(grammar_path)?),
         Some("json") => Ok(fs::read_to_string(grammar_path)?),
-        _ => Err(Error(format!(
+        _ => Err(Error::Generate(format!(
             "Unknown grammar file extension: {:?}",
             grammar_path
         ))),
@@ -140,17 +180,13 @@ fn load_js_grammar_file(grammar_path: &Path) -> Result<String> {
     match output.status.code() {
         None => panic!("Node process was killed"),
         Some(0) => {}
-        Some(code) => return Err(Error(format!("Node process exited with status {}", code))),
+        Some(code) => {
+            return Err(Error::Generate(format!(
+                "Node process exited with status {}",
+                code
+            )))
+        }
     }
 
     Ok(String::from_utf8(output.stdout).expect("Got invalid UTF8 from node"))
 }
-
-fn ensure_file<T: AsRef<[u8]>>(path: &PathBuf, f: impl Fn() -> T) -> Result<()> {
-    if path.exists() {
-        Ok(())
-    } else {
-        fs::write(path, f().as_ref())
-            .map_err(|e| Error(format!("Failed to write file {:?}: {}", path, e)))
-    }
-}
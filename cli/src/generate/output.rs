@@ -0,0 +1,152 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputMode {
+    Write,
+    Check,
+    DryRun,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FileStatus {
+    Create,
+    Overwrite,
+    Unchanged,
+}
+
+pub struct Output {
+    mode: OutputMode,
+    stale_paths: Vec<PathBuf>,
+}
+
+impl Output {
+    pub fn new(mode: OutputMode) -> Self {
+        Output {
+            mode,
+            stale_paths: Vec::new(),
+        }
+    }
+
+    pub fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        if self.mode == OutputMode::Write {
+            fs::create_dir_all(path)?;
+        }
+        Ok(())
+    }
+
+    pub fn write(&mut self, path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+        let contents = contents.as_ref();
+        match self.mode {
+            OutputMode::Write => fs::write(path, contents),
+            OutputMode::Check => {
+                if file_status(path, contents) != FileStatus::Unchanged {
+                    self.stale_paths.push(path.to_owned());
+                }
+                Ok(())
+            }
+            OutputMode::DryRun => {
+                let status = match file_status(path, contents) {
+                    FileStatus::Create => "create",
+                    FileStatus::Overwrite => "overwrite",
+                    FileStatus::Unchanged => "unchanged",
+                };
+                println!(
+                    "{}\t{}\t{} bytes",
+                    status,
+                    path.to_string_lossy(),
+                    contents.len()
+                );
+                Ok(())
+            }
+        }
+    }
+
+    pub fn ensure<T: AsRef<[u8]>>(&mut self, path: &Path, f: impl Fn() -> T) -> io::Result<()> {
+        if path.exists() {
+            Ok(())
+        } else {
+            self.write(path, f())
+        }
+    }
+
+    pub fn stale_paths(&self) -> &[PathBuf] {
+        &self.stale_paths
+    }
+}
+
+fn file_status(path: &Path, contents: &[u8]) -> FileStatus {
+    match fs::read(path) {
+        Ok(ref existing) if existing.as_slice() == contents => FileStatus::Unchanged,
+        Ok(_) => FileStatus::Overwrite,
+        Err(_) => FileStatus::Create,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::fixtures::scratch_dir;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let result = scratch_dir().join("output").join(name);
+        if result.exists() {
+            fs::remove_dir_all(&result).unwrap();
+        }
+        fs::create_dir_all(&result).unwrap();
+        result
+    }
+
+    #[test]
+    fn test_check_mode() {
+        let dir = test_dir("check");
+        let unchanged_path = dir.join("unchanged.txt");
+        let changed_path = dir.join("changed.txt");
+        let missing_path = dir.join("missing.txt");
+        fs::write(&unchanged_path, "same").unwrap();
+        fs::write(&changed_path, "old").unwrap();
+
+        let mut output = Output::new(OutputMode::Check);
+        output.create_dir_all(&dir.join("subdir")).unwrap();
+        output.write(&unchanged_path, "same").unwrap();
+        output.write(&changed_path, "new").unwrap();
+        output.write(&missing_path, "new").unwrap();
+
+        assert_eq!(
+            output.stale_paths(),
+            &[changed_path.clone(), missing_path.clone()]
+        );
+        assert_eq!(fs::read_to_string(&changed_path).unwrap(), "old");
+        assert!(!missing_path.exists());
+        assert!(!dir.join("subdir").exists());
+    }
+
+    #[test]
+    fn test_dry_run_mode() {
+        let dir = test_dir("dry-run");
+        let unchanged_path = dir.join("unchanged.txt");
+        let changed_path = dir.join("changed.txt");
+        let missing_path = dir.join("missing.txt");
+        fs::write(&unchanged_path, "same").unwrap();
+        fs::write(&changed_path, "old").unwrap();
+
+        assert_eq!(file_status(&unchanged_path, b"same"), FileStatus::Unchanged);
+        assert_eq!(file_status(&changed_path, b"new"), FileStatus::Overwrite);
+        assert_eq!(file_status(&missing_path, b"new"), FileStatus::Create);
+
+        let mut output = Output::new(OutputMode::DryRun);
+        output.create_dir_all(&dir.join("subdir")).unwrap();
+        output.write(&unchanged_path, "same").unwrap();
+        output.write(&changed_path, "new").unwrap();
+        output.write(&missing_path, "new").unwrap();
+        output
+            .ensure(&dir.join("subdir").join("ensured.txt"), || "new")
+            .unwrap();
+
+        assert!(output.stale_paths().is_empty());
+        assert_eq!(fs::read_to_string(&changed_path).unwrap(), "old");
+        assert!(!missing_path.exists());
+        assert!(!dir.join("subdir").exists());
+    }
+}
@@ -98,7 +98,7 @@ pub(crate) fn expand_tokens(mut grammar: ExtractedLexicalGrammar) -> Result<Lexi
         let last_state_id = builder.nfa.last_state_id();
         builder
             .expand_rule(&variable.rule, last_state_id)
-            .map_err(|Error(msg)| Error(format!("Rule {} {}", variable.name, msg)))?;
+            .map_err(|e| Error::Generate(format!("Rule {} {}", variable.name, e)))?;
 
         if !is_immediate_token {
             builder.is_sep = true;
@@ -127,7 +127,7 @@ impl NfaBuilder {
                 let s = preprocess_regex(s);
                 let ast = parse::Parser::new()
                     .parse(&s)
-                    .map_err(|e| Error(e.to_string()))?;
+                    .map_err(|e| Error::Generate(e.to_string()))?;
                 self.expand_regex(&ast, next_state_id)
             }
             Rule::String(s) => {
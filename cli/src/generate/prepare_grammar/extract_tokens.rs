@@ -89,7 +89,7 @@ pub(super) fn extract_tokens(
         if let Rule::Symbol(symbol) = rule {
             let new_symbol = symbol_replacer.replace_symbol(symbol);
             if new_symbol.is_non_terminal() {
-                return Err(Error(format!(
+                return Err(Error::Generate(format!(
                     "Non-token symbol '{}' cannot be used as an extra token",
                     &variables[new_symbol.index].name
                 )));
@@ -110,7 +110,7 @@ pub(super) fn extract_tokens(
         let rule = symbol_replacer.replace_symbols_in_rule(&external_token.rule);
         if let Rule::Symbol(symbol) = rule {
             if symbol.is_non_terminal() {
-                return Err(Error(format!(
+                return Err(Error::Generate(format!(
                     "Rule '{}' cannot be used as both an external token and a non-terminal rule",
                     &variables[symbol.index].name,
                 )));
@@ -130,7 +130,7 @@ pub(super) fn extract_tokens(
                 })
             }
         } else {
-            return Err(Error(format!(
+            return Err(Error::Generate(format!(
                 "Non-symbol rules cannot be used as external tokens"
             )));
         }
@@ -140,7 +140,7 @@ pub(super) fn extract_tokens(
     if let Some(token) = grammar.word_token {
         let token = symbol_replacer.replace_symbol(token);
         if token.is_non_terminal() {
-            return Err(Error(format!(
+            return Err(Error::Generate(format!(
                 "Non-terminal symbol '{}' cannot be used as the word token",
                 &variables[token.index].name
             )));
@@ -475,7 +475,7 @@ mod test {
         grammar.extra_tokens = vec![Rule::non_terminal(1)];
 
         match extract_tokens(grammar) {
-            Err(Error(s)) => {
+            Err(Error::Generate(s)) => {
                 assert_eq!(
                     s,
                     "Non-token symbol 'rule_1' cannot be used as an extra token"
@@ -503,7 +503,7 @@ mod test {
         grammar.external_tokens = vec![Variable::named("rule_1", Rule::non_terminal(1))];
 
         match extract_tokens(grammar) {
-            Err(Error(s)) => {
+            Err(Error::Generate(s)) => {
                 assert_eq!(s, "Rule 'rule_1' cannot be used as both an external token and a non-terminal rule");
             }
             _ => {
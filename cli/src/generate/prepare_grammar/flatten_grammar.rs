@@ -174,7 +174,7 @@ pub(super) fn flatten_grammar(grammar: ExtractedSyntaxGrammar) -> Result<SyntaxG
     for (i, variable) in variables.iter().enumerate() {
         for production in &variable.productions {
             if production.steps.is_empty() && symbol_is_used(&variables, Symbol::non_terminal(i)) {
-                return Err(Error(format!(
+                return Err(Error::Generate(format!(
                     "The rule `{}` matches the empty string.
 
 Tree-sitter does not support syntactic rules that match the empty string
@@ -0,0 +1,48 @@
+use std::time::{Duration, Instant};
+
+pub struct Profile {
+    phases: Option<Vec<(&'static str, Duration)>>,
+    phase_start: Instant,
+}
+
+impl Profile {
+    pub fn new(enabled: bool) -> Self {
+        Profile {
+            phases: if enabled { Some(Vec::new()) } else { None },
+            phase_start: Instant::now(),
+        }
+    }
+
+    pub fn end_phase(&mut self, name: &'static str) {
+        if let Some(phases) = self.phases.as_mut() {
+            phases.push((name, self.phase_start.elapsed()));
+            self.phase_start = Instant::now();
+        }
+    }
+
+    pub fn print(&self) {
+        if let Some(phases) = self.phases.as_ref() {
+            let name_width = phases.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+            let mut total = Duration::new(0, 0);
+            for (name, duration) in phases {
+                eprintln!(
+                    "{:width$}\t{} ms",
+                    name,
+                    duration_ms(duration),
+                    width = name_width
+                );
+                total += *duration;
+            }
+            eprintln!(
+                "{:width$}\t{} ms",
+                "total",
+                duration_ms(&total),
+                width = name_width
+            );
+        }
+    }
+}
+
+fn duration_ms(duration: &Duration) -> u64 {
+    duration.as_secs() * 1000 + duration.subsec_nanos() as u64 / 1000000
+}
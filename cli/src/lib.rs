@@ -1,3 +1,4 @@
+pub mod config;
 pub mod error;
 pub mod generate;
 pub mod loader;
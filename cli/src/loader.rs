@@ -204,7 +204,7 @@ impl Loader {
 
             let output = command.output()?;
             if !output.status.success() {
-                return Err(Error(format!(
+                return Err(Error::Language(format!(
                     "Parser compilation failed.\nStdout: {}\nStderr: {}",
                     String::from_utf8_lossy(&output.stdout),
                     String::from_utf8_lossy(&output.stderr)
@@ -213,7 +213,7 @@ impl Loader {
         }
 
         let library = Library::new(&library_path).map_err(|e| {
-            Error(format!(
+            Error::Language(format!(
                 "Error opening dynamic library {:?}: {}",
                 &library_path, e
             ))
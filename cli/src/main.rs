@@ -4,12 +4,13 @@ use std::fs;
 use std::path::Path;
 use std::process::exit;
 use std::usize;
+use tree_sitter::Point;
 use tree_sitter_cli::loader::Loader;
-use tree_sitter_cli::{error, generate, logger, parse, properties, test};
+use tree_sitter_cli::{config, error, generate, logger, parse, properties, test};
 
 fn main() {
     if let Err(e) = run() {
-        eprintln!("{}", e.0);
+        eprintln!("{}", e);
         exit(1);
     }
 }
@@ -36,7 +37,15 @@ fn run() -> error::Result<()> {
                         .long("log-state")
                         .takes_value(true),
                 )
-                .arg(Arg::with_name("no-minimize").long("no-minimize")),
+                .arg(Arg::with_name("no-minimize").long("no-minimize"))
+                .arg(Arg::with_name("list-conflicts").long("list-conflicts"))
+                .arg(Arg::with_name("profile").long("profile"))
+                .arg(Arg::with_name("check").long("check"))
+                .arg(
+                    Arg::with_name("dry-run")
+                        .long("dry-run")
+                        .conflicts_with("check"),
+                ),
         )
         .subcommand(
             SubCommand::with_name("parse")
@@ -50,7 +59,30 @@ fn run() -> error::Result<()> {
                 .arg(Arg::with_name("debug").long("debug").short("d"))
                 .arg(Arg::with_name("debug-graph").long("debug-graph").short("D"))
                 .arg(Arg::with_name("quiet").long("quiet").short("q"))
-                .arg(Arg::with_name("time").long("time").short("t")),
+                .arg(Arg::with_name("time").long("time").short("t"))
+                .arg(Arg::with_name("include-whitespace").long("include-whitespace"))
+                .arg(
+                    Arg::with_name("compact")
+                        .long("compact")
+                        .conflicts_with("include-whitespace"),
+                )
+                .arg(Arg::with_name("symbols").long("symbols"))
+                .arg(Arg::with_name("at").long("at").takes_value(true))
+                .arg(Arg::with_name("ranges").long("ranges").takes_value(true))
+                .arg(Arg::with_name("timeout").long("timeout").takes_value(true))
+                .arg(
+                    Arg::with_name("paths")
+                        .long("paths")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1),
+                )
+                .arg(
+                    Arg::with_name("repeat")
+                        .long("repeat")
+                        .takes_value(true)
+                        .hidden(true),
+                ),
         )
         .subcommand(
             SubCommand::with_name("test")
@@ -62,7 +94,25 @@ fn run() -> error::Result<()> {
                         .takes_value(true),
                 )
                 .arg(Arg::with_name("debug").long("debug").short("d"))
-                .arg(Arg::with_name("debug-graph").long("debug-graph").short("D")),
+                .arg(Arg::with_name("debug-graph").long("debug-graph").short("D"))
+                .arg(Arg::with_name("timeout").long("timeout").takes_value(true))
+                .arg(
+                    Arg::with_name("corpus")
+                        .long("corpus")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1),
+                )
+                .arg(
+                    Arg::with_name("output-junit")
+                        .long("output-junit")
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("version")
+                .about("Print version information")
+                .arg(Arg::with_name("json").long("json")),
         )
         .get_matches();
 
@@ -71,9 +121,24 @@ fn run() -> error::Result<()> {
     let config_dir = home_dir.join(".tree-sitter");
 
     fs::create_dir_all(&config_dir).unwrap();
-    let mut loader = Loader::new(config_dir);
+    let mut loader = Loader::new(config_dir.clone());
 
-    if let Some(matches) = matches.subcommand_matches("generate") {
+    if let Some(matches) = matches.subcommand_matches("version") {
+        if matches.is_present("json") {
+            let info = serde_json::json!({
+                "version": env!("CARGO_PKG_VERSION"),
+                "commit": env!("BUILD_SHA").trim(),
+                "abi": tree_sitter::LANGUAGE_VERSION,
+            });
+            println!("{}", info);
+        } else {
+            println!(
+                "tree-sitter {} ({})",
+                env!("CARGO_PKG_VERSION"),
+                env!("BUILD_SHA").trim()
+            );
+        }
+    } else if let Some(matches) = matches.subcommand_matches("generate") {
         if matches.is_present("log") {
             logger::init();
         }
@@ -81,6 +146,15 @@ fn run() -> error::Result<()> {
         let grammar_path = matches.value_of("grammar-path");
         let minimize = !matches.is_present("no-minimize");
         let properties_only = matches.is_present("properties-only");
+        let log_conflicts = matches.is_present("list-conflicts");
+        let mut profile = generate::Profile::new(matches.is_present("profile"));
+        let mut output = generate::Output::new(if matches.is_present("dry-run") {
+            generate::OutputMode::DryRun
+        } else if matches.is_present("check") {
+            generate::OutputMode::Check
+        } else {
+            generate::OutputMode::Write
+        });
         let state_ids_to_log = matches
             .values_of("state-ids-to-log")
             .map_or(Vec::new(), |ids| {
@@ -93,16 +167,47 @@ fn run() -> error::Result<()> {
                 grammar_path,
                 minimize,
                 state_ids_to_log,
+                log_conflicts,
+                &mut profile,
+                &mut output,
             )?;
         }
-        properties::generate_property_sheets_in_directory(&current_dir)?;
+        properties::generate_property_sheets_in_directory(&current_dir, &mut output)?;
+        profile.end_phase("generate property sheets");
+        profile.print();
+        if !output.stale_paths().is_empty() {
+            for path in output.stale_paths() {
+                eprintln!("{} is out of date", path.to_string_lossy());
+            }
+            return Err(error::Error::Generate(
+                "Generated files are out of date".to_string(),
+            ));
+        }
     } else if let Some(matches) = matches.subcommand_matches("test") {
         let debug = matches.is_present("debug");
         let debug_graph = matches.is_present("debug-graph");
         let filter = matches.value_of("filter");
-        let corpus_path = current_dir.join("corpus");
+        let timeout_micros = match matches.value_of("timeout") {
+            Some(n) => u64::from_str_radix(n, 10)
+                .map_err(|_| error::Error::Other(format!("Invalid timeout {:?}", n)))?,
+            None => test::DEFAULT_TIMEOUT_MICROS,
+        };
+        let junit_path = matches.value_of("output-junit").map(Path::new);
+        let corpus_paths = matches
+            .values_of("corpus")
+            .map_or(vec![current_dir.join("corpus")], |paths| {
+                paths.map(|path| current_dir.join(path)).collect()
+            });
         if let Some(language) = loader.language_at_path(&current_dir)? {
-            test::run_tests_at_path(language, &corpus_path, debug, debug_graph, filter)?;
+            test::run_tests_at_path(
+                language,
+                &corpus_paths,
+                debug,
+                debug_graph,
+                filter,
+                timeout_micros,
+                junit_path,
+            )?;
         } else {
             eprintln!("No language found");
         }
@@ -111,7 +216,73 @@ fn run() -> error::Result<()> {
         let debug_graph = matches.is_present("debug-graph");
         let quiet = matches.is_present("quiet");
         let time = matches.is_present("time");
-        loader.find_all_languages(&vec![home_dir.join("github")])?;
+        let include_extras = matches.is_present("include-whitespace");
+        let compact = matches.is_present("compact");
+        let symbols = matches.is_present("symbols");
+        let at = match matches.value_of("at") {
+            Some(position) => {
+                let mut parts = position
+                    .split(',')
+                    .map(|n| usize::from_str_radix(n.trim(), 10));
+                match (parts.next(), parts.next(), parts.next()) {
+                    (Some(Ok(row)), Some(Ok(column)), None) => Some(Point::new(row, column)),
+                    _ => {
+                        return Err(error::Error::Other(format!(
+                            "Invalid position {:?}. Expected `row,column`",
+                            position
+                        )))
+                    }
+                }
+            }
+            None => None,
+        };
+        let ranges = match matches.value_of("ranges") {
+            Some(ranges) => ranges
+                .split(',')
+                .map(|range| {
+                    let mut parts = range
+                        .split(':')
+                        .map(|n| usize::from_str_radix(n.trim(), 10));
+                    match (parts.next(), parts.next(), parts.next()) {
+                        (Some(Ok(start)), Some(Ok(end)), None) => Ok((start, end)),
+                        _ => Err(error::Error::Other(format!(
+                            "Invalid range {:?}. Expected `start:end`",
+                            range
+                        ))),
+                    }
+                })
+                .collect::<error::Result<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+        let timeout_micros = match matches.value_of("timeout") {
+            Some(n) => u64::from_str_radix(n, 10)
+                .map_err(|_| error::Error::Other(format!("Invalid timeout {:?}", n)))?,
+            None => 0,
+        };
+        let repeat = match matches.value_of("repeat") {
+            Some(n) => usize::from_str_radix(n, 10)
+                .map_err(|_| error::Error::Other(format!("Invalid repeat count {:?}", n)))?,
+            None => 1,
+        };
+        let options = parse::ParseOptions {
+            quiet,
+            print_time: time,
+            debug,
+            debug_graph,
+            include_extras,
+            compact,
+            symbols,
+            at,
+            byte_ranges: &ranges,
+            timeout_micros,
+            repeat,
+        };
+        let parser_directories = config::Config::load(&config_dir)?.parser_directories(
+            matches.values_of("paths").into_iter().flatten(),
+            &home_dir,
+            &current_dir,
+        );
+        loader.find_all_languages(&parser_directories)?;
         let paths = matches
             .values_of("path")
             .unwrap()
@@ -130,19 +301,11 @@ fn run() -> error::Result<()> {
                     eprintln!("No language found");
                     return Ok(());
                 };
-            has_error |= parse::parse_file_at_path(
-                language,
-                path,
-                max_path_length,
-                quiet,
-                time,
-                debug,
-                debug_graph,
-            )?;
+            has_error |= parse::parse_file_at_path(language, path, max_path_length, &options)?;
         }
 
         if has_error {
-            return Err(error::Error(String::new()));
+            return Err(error::Error::Parse);
         }
     }
 
@@ -1,24 +1,100 @@
-use super::error::Result;
+use super::error::{Error, Result};
 use super::util;
-use std::fs;
+use memmap2::Mmap;
+use std::fs::{self, File};
 use std::io::{self, Write};
 use std::path::Path;
 use std::time::Instant;
-use tree_sitter::{Language, LogType, Parser};
+use tree_sitter::{Language, LogType, Node, Parser, Point, Range, Tree};
+
+// Files at least this large are memory-mapped rather than read into a buffer.
+pub(crate) const MMAP_THRESHOLD: u64 = 4 * 1024 * 1024;
+
+const UTF8_BOM: &'static [u8] = b"\xEF\xBB\xBF";
+
+enum SourceCode {
+    Mapped(Mmap),
+    Buffered(Vec<u8>),
+}
+
+// A leading byte order mark is not part of the source code, so it is skipped.
+// Byte offsets are therefore relative to the end of the BOM, if there is one.
+impl AsRef<[u8]> for SourceCode {
+    fn as_ref(&self) -> &[u8] {
+        let bytes: &[u8] = match self {
+            SourceCode::Mapped(map) => map,
+            SourceCode::Buffered(bytes) => bytes,
+        };
+        if bytes.starts_with(UTF8_BOM) {
+            &bytes[UTF8_BOM.len()..]
+        } else {
+            bytes
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseOptions<'a> {
+    pub quiet: bool,
+    pub print_time: bool,
+    pub debug: bool,
+    pub debug_graph: bool,
+    pub include_extras: bool,
+    pub compact: bool,
+    pub symbols: bool,
+    pub at: Option<Point>,
+    pub byte_ranges: &'a [(usize, usize)],
+    pub timeout_micros: u64,
+    pub repeat: usize,
+}
+
+impl<'a> Default for ParseOptions<'a> {
+    fn default() -> Self {
+        ParseOptions {
+            quiet: false,
+            print_time: false,
+            debug: false,
+            debug_graph: false,
+            include_extras: false,
+            compact: false,
+            symbols: false,
+            at: None,
+            byte_ranges: &[],
+            timeout_micros: 0,
+            repeat: 1,
+        }
+    }
+}
 
 pub fn parse_file_at_path(
     language: Language,
     path: &Path,
     max_path_length: usize,
-    quiet: bool,
-    print_time: bool,
-    debug: bool,
-    debug_graph: bool,
+    options: &ParseOptions,
 ) -> Result<bool> {
+    let ParseOptions {
+        quiet,
+        print_time,
+        debug,
+        debug_graph,
+        include_extras,
+        compact,
+        symbols,
+        at,
+        byte_ranges,
+        timeout_micros,
+        repeat,
+    } = *options;
+
     let mut _log_session = None;
     let mut parser = Parser::new();
-    parser.set_language(language)?;
-    let source_code = fs::read(path)?;
+    parser.set_language(language).map_err(Error::Language)?;
+    parser.set_timeout_micros(timeout_micros);
+    let source_code = read_source_code(path)?;
+
+    if !byte_ranges.is_empty() {
+        parser.set_included_ranges(&included_ranges(source_code.as_ref(), byte_ranges)?);
+    }
 
     if debug_graph {
         _log_session = Some(util::log_graphs(&mut parser, "log.html")?);
@@ -32,70 +108,70 @@ pub fn parse_file_at_path(
     }
 
     let time = Instant::now();
-    let tree = parser
-        .parse(&source_code, None)
-        .expect("Incompatible language version");
+    let tree = match parser.parse(&source_code, None) {
+        Some(tree) => tree,
+        None => {
+            println!(
+                "{:width$}\tparse timed out after {} microseconds",
+                path.to_str().unwrap(),
+                timeout_micros,
+                width = max_path_length
+            );
+            return Ok(true);
+        }
+    };
     let duration = time.elapsed();
     let duration_ms = duration.as_secs() * 1000 + duration.subsec_nanos() as u64 / 1000000;
 
-    let mut cursor = tree.walk();
+    let mut has_divergence = false;
+    if repeat > 1 {
+        for i in 1..repeat {
+            let repeated_tree = parser.parse(&source_code, None);
+            if repeated_tree.is_none() {
+                parser.reset();
+            }
+            if !repeated_tree.map_or(false, |repeated_tree| {
+                trees_are_identical(&tree, &repeated_tree)
+            }) {
+                eprintln!(
+                    "{}: parse {} of {} produced a different tree",
+                    path.to_str().unwrap(),
+                    i + 1,
+                    repeat
+                );
+                has_divergence = true;
+            }
+        }
+    }
 
     let stdout = io::stdout();
     let mut stdout = stdout.lock();
 
-    if !quiet {
-        let mut needs_newline = false;
-        let mut indent_level = 0;
-        let mut did_visit_children = false;
-        loop {
-            let node = cursor.node();
-            let is_named = node.is_named();
-            if did_visit_children {
-                if is_named {
-                    stdout.write(b")")?;
-                    needs_newline = true;
-                }
-                if cursor.goto_next_sibling() {
-                    did_visit_children = false;
-                } else if cursor.goto_parent() {
-                    did_visit_children = true;
-                    indent_level -= 1;
-                } else {
-                    break;
-                }
-            } else {
-                if is_named {
-                    if needs_newline {
-                        stdout.write(b"\n")?;
-                    }
-                    for _ in 0..indent_level {
-                        stdout.write(b"  ")?;
-                    }
-                    let start = node.start_position();
-                    let end = node.end_position();
-                    write!(
-                        &mut stdout,
-                        "({} [{}, {}] - [{}, {}]",
-                        node.kind(),
-                        start.row,
-                        start.column,
-                        end.row,
-                        end.column
-                    )?;
-                    needs_newline = true;
-                }
-                if cursor.goto_first_child() {
-                    did_visit_children = false;
-                    indent_level += 1;
-                } else {
-                    did_visit_children = true;
-                }
-            }
+    let mut print_root = tree.root_node();
+    if let Some(point) = at {
+        if let Some(node) = print_root.named_descendant_for_point_range(point, point) {
+            print_root = node;
+        }
+        if !quiet {
+            write!(
+                &mut stdout,
+                "{} [{} - {}]\n",
+                print_root.kind(),
+                print_root.start_byte(),
+                print_root.end_byte()
+            )?;
         }
-        cursor.reset(tree.root_node());
-        println!("");
     }
 
+    if !quiet {
+        print_tree(print_root, compact, include_extras, &mut stdout)?;
+    }
+
+    if symbols {
+        print_symbol_usage(language, &tree, &mut stdout)?;
+    }
+
+    let mut cursor = tree.walk();
     let mut first_error = None;
     loop {
         let node = cursor.node();
@@ -137,5 +213,270 @@ pub fn parse_file_at_path(
         write!(&mut stdout, "\n")?;
     }
 
-    Ok(first_error.is_some())
+    Ok(first_error.is_some() || has_divergence)
+}
+
+// Unlike comparing S-expressions, this also detects nodes whose kinds match
+// but whose positions differ.
+fn trees_are_identical(a: &Tree, b: &Tree) -> bool {
+    let mut a = a.walk();
+    let mut b = b.walk();
+    loop {
+        if a.node().kind_id() != b.node().kind_id() || a.node().range() != b.node().range() {
+            return false;
+        }
+        let has_child = a.goto_first_child();
+        if has_child != b.goto_first_child() {
+            return false;
+        }
+        if has_child {
+            continue;
+        }
+        loop {
+            let has_sibling = a.goto_next_sibling();
+            if has_sibling != b.goto_next_sibling() {
+                return false;
+            }
+            if has_sibling {
+                break;
+            }
+            if !a.goto_parent() {
+                return true;
+            }
+            b.goto_parent();
+        }
+    }
+}
+
+pub(crate) fn print_tree(
+    root: Node,
+    compact: bool,
+    include_extras: bool,
+    stdout: &mut impl Write,
+) -> Result<()> {
+    if compact {
+        write!(stdout, "{}\n", root.to_sexp())?;
+        return Ok(());
+    }
+
+    let mut cursor = root.walk();
+    let mut needs_newline = false;
+    let mut indent_level = 0;
+    let mut did_visit_children = false;
+    loop {
+        let node = cursor.node();
+        let is_visible = node.is_named() || (include_extras && node.is_extra());
+        if did_visit_children {
+            if is_visible {
+                stdout.write(b")")?;
+                needs_newline = true;
+            }
+            if cursor.goto_next_sibling() {
+                did_visit_children = false;
+            } else if cursor.goto_parent() {
+                did_visit_children = true;
+                indent_level -= 1;
+            } else {
+                break;
+            }
+        } else {
+            if is_visible {
+                if needs_newline {
+                    stdout.write(b"\n")?;
+                }
+                for _ in 0..indent_level {
+                    stdout.write(b"  ")?;
+                }
+                let start = node.start_position();
+                let end = node.end_position();
+                if node.is_named() {
+                    write!(stdout, "({}", node.kind())?;
+                } else {
+                    write!(stdout, "(\"{}\"", node.kind())?;
+                }
+                write!(
+                    stdout,
+                    " [{}, {}] - [{}, {}]",
+                    start.row, start.column, end.row, end.column
+                )?;
+                needs_newline = true;
+            }
+            if cursor.goto_first_child() {
+                did_visit_children = false;
+                indent_level += 1;
+            } else {
+                did_visit_children = true;
+            }
+        }
+    }
+    stdout.write(b"\n")?;
+    Ok(())
+}
+
+pub(crate) fn print_symbol_usage(
+    language: Language,
+    tree: &Tree,
+    stdout: &mut impl Write,
+) -> Result<()> {
+    let symbol_count = language.node_kind_count();
+    let mut used = vec![false; symbol_count];
+    let mut cursor = tree.walk();
+    loop {
+        let kind_id = cursor.node().kind_id() as usize;
+        if kind_id < symbol_count {
+            used[kind_id] = true;
+        }
+        if !cursor.goto_first_child() {
+            while !cursor.goto_next_sibling() {
+                if !cursor.goto_parent() {
+                    break;
+                }
+            }
+        }
+        if cursor.node() == tree.root_node() {
+            break;
+        }
+    }
+
+    let visible_ids = (0..symbol_count as u16)
+        .filter(|id| language.node_kind_is_visible(*id))
+        .collect::<Vec<_>>();
+    let used_count = visible_ids.iter().filter(|id| used[**id as usize]).count();
+    write!(
+        stdout,
+        "used {} of {} symbols\n",
+        used_count,
+        visible_ids.len()
+    )?;
+    for id in visible_ids {
+        if !used[id as usize] {
+            if language.node_kind_is_named(id) {
+                write!(stdout, "  unused: {}\n", language.node_kind_for_id(id))?;
+            } else {
+                write!(stdout, "  unused: \"{}\"\n", language.node_kind_for_id(id))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn included_ranges(source_code: &[u8], byte_ranges: &[(usize, usize)]) -> Result<Vec<Range>> {
+    let mut result = Vec::with_capacity(byte_ranges.len());
+    for (i, (start_byte, end_byte)) in byte_ranges.iter().cloned().enumerate() {
+        if end_byte < start_byte {
+            return Err(Error::Other(format!(
+                "Range {} ({}:{}) ends before it starts",
+                i, start_byte, end_byte
+            )));
+        }
+        if end_byte > source_code.len() {
+            return Err(Error::Other(format!(
+                "Range {} ({}:{}) extends past the end of the file ({} bytes)",
+                i,
+                start_byte,
+                end_byte,
+                source_code.len()
+            )));
+        }
+        if i > 0 {
+            let (previous_start_byte, previous_end_byte) = byte_ranges[i - 1];
+            if start_byte < previous_end_byte {
+                return Err(Error::Other(format!(
+                    "Range {} ({}:{}) must start after range {} ({}:{}) ends",
+                    i,
+                    start_byte,
+                    end_byte,
+                    i - 1,
+                    previous_start_byte,
+                    previous_end_byte
+                )));
+            }
+        }
+        result.push(Range {
+            start_byte,
+            end_byte,
+            start_point: point_for_byte(source_code, start_byte),
+            end_point: point_for_byte(source_code, end_byte),
+        });
+    }
+    Ok(result)
+}
+
+fn point_for_byte(source_code: &[u8], byte: usize) -> Point {
+    let preceding = &source_code[0..byte];
+    let row = preceding.iter().filter(|c| **c == b'\n').count();
+    let column = match preceding.iter().rposition(|c| *c == b'\n') {
+        Some(newline) => byte - newline - 1,
+        None => byte,
+    };
+    Point::new(row, column)
+}
+
+fn read_source_code(path: &Path) -> Result<SourceCode> {
+    let file = File::open(path)?;
+    if file.metadata()?.len() >= MMAP_THRESHOLD {
+        // The map is only ever read. Like any memory-mapped read, this assumes that
+        // the file is not truncated by another process while it is being parsed.
+        if let Ok(map) = unsafe { Mmap::map(&file) } {
+            return Ok(SourceCode::Mapped(map));
+        }
+    }
+    Ok(SourceCode::Buffered(fs::read(path)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_included_ranges() {
+        let source_code = b"abc\ndef\nghi";
+        let ranges = included_ranges(source_code, &[(1, 2), (5, 9)]).unwrap();
+        assert_eq!(
+            ranges,
+            vec![
+                Range {
+                    start_byte: 1,
+                    end_byte: 2,
+                    start_point: Point::new(0, 1),
+                    end_point: Point::new(0, 2),
+                },
+                Range {
+                    start_byte: 5,
+                    end_byte: 9,
+                    start_point: Point::new(1, 1),
+                    end_point: Point::new(2, 1),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_included_ranges_with_invalid_ranges() {
+        let source_code = b"abc\ndef\nghi";
+        assert_eq!(
+            included_ranges(source_code, &[(0, 2), (4, 3)])
+                .unwrap_err()
+                .to_string(),
+            "Range 1 (4:3) ends before it starts"
+        );
+        assert_eq!(
+            included_ranges(source_code, &[(0, 5), (3, 7)])
+                .unwrap_err()
+                .to_string(),
+            "Range 1 (3:7) must start after range 0 (0:5) ends"
+        );
+        assert_eq!(
+            included_ranges(source_code, &[(6, 8), (0, 2)])
+                .unwrap_err()
+                .to_string(),
+            "Range 1 (0:2) must start after range 0 (6:8) ends"
+        );
+        assert_eq!(
+            included_ranges(source_code, &[(0, 20)])
+                .unwrap_err()
+                .to_string(),
+            "Range 0 (0:20) extends past the end of the file (11 bytes)"
+        );
+    }
 }
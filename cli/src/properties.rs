@@ -1,4 +1,5 @@
 use crate::error::{Error, Result};
+use crate::generate::Output;
 use log::info;
 use rsass;
 use rsass::sass::Value;
@@ -7,8 +8,7 @@ use std::cmp::Ordering;
 use std::collections::hash_map::Entry;
 use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::fmt::{self, Write};
-use std::fs::{self, File};
-use std::io::BufWriter;
+use std::fs;
 use std::path::{Path, PathBuf};
 use tree_sitter::{self, PropertyStateJSON, PropertyTransitionJSON};
 
@@ -422,7 +422,7 @@ impl fmt::Debug for Selector {
     }
 }
 
-pub fn generate_property_sheets_in_directory(repo_path: &Path) -> Result<()> {
+pub fn generate_property_sheets_in_directory(repo_path: &Path, output: &mut Output) -> Result<()> {
     let src_dir_path = repo_path.join("src");
     let properties_dir_path = repo_path.join("properties");
 
@@ -434,10 +434,17 @@ pub fn generate_property_sheets_in_directory(repo_path: &Path) -> Result<()> {
             let property_sheet_json_path = src_dir_path
                 .join(css_path.file_name().unwrap())
                 .with_extension("json");
-            let property_sheet_json_file = File::create(&property_sheet_json_path)
-                .map_err(|e| format!("Failed to create {:?}: {}", property_sheet_json_path, e))?;
-            let mut writer = BufWriter::new(property_sheet_json_file);
-            serde_json::to_writer_pretty(&mut writer, &sheet)?;
+            output
+                .write(
+                    &property_sheet_json_path,
+                    serde_json::to_vec_pretty(&sheet)?,
+                )
+                .map_err(|e| {
+                    Error::WriteFile(
+                        format!("Failed to create {:?}", property_sheet_json_path),
+                        e,
+                    )
+                })?;
         }
     }
 
@@ -466,7 +473,9 @@ fn parse_property_sheet(path: &Path, css: &str) -> Result<Vec<Rule>> {
                     items.splice(i..(i + 1), imported_items);
                     continue;
                 } else {
-                    return Err(Error("@import arguments must be strings".to_string()));
+                    return Err(Error::PropertySheet(
+                        "@import arguments must be strings".to_string(),
+                    ));
                 }
             }
             rsass::Item::AtRule { name, args, .. } => match name.as_str() {
@@ -477,10 +486,17 @@ fn parse_property_sheet(path: &Path, css: &str) -> Result<Vec<Rule>> {
                         items.remove(i);
                         continue;
                     } else {
-                        return Err(Error("@schema arguments must be strings".to_string()));
+                        return Err(Error::PropertySheet(
+                            "@schema arguments must be strings".to_string(),
+                        ));
                     }
                 }
-                _ => return Err(Error(format!("Unsupported at-rule '{}'", name))),
+                _ => {
+                    return Err(Error::PropertySheet(format!(
+                        "Unsupported at-rule '{}'",
+                        name
+                    )))
+                }
             },
             _ => {}
         }
@@ -577,7 +593,12 @@ fn parse_sass_items(
                 }
                 parse_sass_items(items, &full_selectors, result)?;
             }
-            _ => return Err(Error(format!("Unsupported syntax type {:?}", item))),
+            _ => {
+                return Err(Error::PropertySheet(format!(
+                    "Unsupported syntax type {:?}",
+                    item
+                )))
+            }
         }
     }
 
@@ -597,7 +618,9 @@ fn parse_sass_value(value: &Value) -> Result<PropertyValue> {
             if let Some(s) = s.single_raw() {
                 Ok(PropertyValue::String(s.to_string()))
             } else {
-                Err(Error("String interpolation is not supported".to_string()))
+                Err(Error::PropertySheet(
+                    "String interpolation is not supported".to_string(),
+                ))
             }
         }
         Value::Call(name, raw_args) => {
@@ -611,7 +634,9 @@ fn parse_sass_value(value: &Value) -> Result<PropertyValue> {
                 result.insert("args".to_string(), PropertyValue::Array(args));
                 Ok(PropertyValue::Object(result))
             } else {
-                Err(Error("String interpolation is not supported".to_string()))
+                Err(Error::PropertySheet(
+                    "String interpolation is not supported".to_string(),
+                ))
             }
         }
         Value::List(elements, ..) => {
@@ -625,7 +650,7 @@ fn parse_sass_value(value: &Value) -> Result<PropertyValue> {
         Value::Numeric(n, _) => Ok(PropertyValue::Number(n.to_integer())),
         Value::True => Ok(PropertyValue::String("true".to_string())),
         Value::False => Ok(PropertyValue::String("false".to_string())),
-        _ => Err(Error(format!(
+        _ => Err(Error::PropertySheet(format!(
             "Property values must be strings or function calls. Got {:?}",
             value
         ))),
@@ -663,7 +688,10 @@ fn resolve_path(base: &Path, p: &str) -> Result<PathBuf> {
             }
         }
     }
-    Err(Error(format!("Could not resolve import path `{}`", p)))
+    Err(Error::PropertySheet(format!(
+        "Could not resolve import path `{}`",
+        p
+    )))
 }
 
 #[cfg(test)]
@@ -8,8 +8,9 @@ use regex::Regex;
 use std::char;
 use std::fs;
 use std::io::{self, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str;
+use std::time::{Duration, Instant};
 use tree_sitter::{Language, LogType, Parser};
 
 lazy_static! {
@@ -24,6 +25,8 @@ lazy_static! {
     static ref WHITESPACE_REGEX: Regex = Regex::new(r"\s+").unwrap();
 }
 
+pub const DEFAULT_TIMEOUT_MICROS: u64 = 10 * 1000 * 1000;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum TestEntry {
     Group {
@@ -46,17 +49,33 @@ impl Default for TestEntry {
     }
 }
 
+pub struct TestResult {
+    pub group: String,
+    pub name: String,
+    pub duration: Duration,
+    pub expected: String,
+    pub actual: Option<String>,
+}
+
+impl TestResult {
+    pub fn passed(&self) -> bool {
+        self.actual.as_ref() == Some(&self.expected)
+    }
+}
+
 pub fn run_tests_at_path(
     language: Language,
-    path: &Path,
+    corpus_paths: &[PathBuf],
     debug: bool,
     debug_graph: bool,
     filter: Option<&str>,
+    timeout_micros: u64,
+    junit_path: Option<&Path>,
 ) -> Result<()> {
-    let test_entry = parse_tests(path)?;
     let mut _log_session = None;
     let mut parser = Parser::new();
-    parser.set_language(language)?;
+    parser.set_language(language).map_err(Error::Language)?;
+    parser.set_timeout_micros(timeout_micros);
 
     if debug_graph {
         _log_session = Some(util::log_graphs(&mut parser, "log.html")?);
@@ -69,13 +88,23 @@ pub fn run_tests_at_path(
         })));
     }
 
-    let mut failures = Vec::new();
-    if let TestEntry::Group { children, .. } = test_entry {
-        for child in children {
-            run_tests(&mut parser, child, filter, 0, &mut failures)?;
+    let mut results = Vec::new();
+    for corpus_path in corpus_paths {
+        if let TestEntry::Group { children, .. } = parse_tests(corpus_path)? {
+            for child in children {
+                run_tests(&mut parser, child, filter, 0, "", &mut results)?;
+            }
         }
     }
 
+    if let Some(junit_path) = junit_path {
+        fs::write(junit_path, junit_xml(&results, timeout_micros))?;
+    }
+
+    let failures = results
+        .iter()
+        .filter(|result| !result.passed())
+        .collect::<Vec<_>>();
     if failures.len() > 0 {
         println!("");
 
@@ -86,11 +115,15 @@ pub fn run_tests_at_path(
         }
 
         print_diff_key();
-        for (i, (name, actual, expected)) in failures.iter().enumerate() {
-            println!("\n  {}. {}:", i + 1, name);
-            print_diff(actual, expected);
+        for (i, failure) in failures.iter().enumerate() {
+            println!("\n  {}. {}:", i + 1, failure.name);
+            if let Some(actual) = &failure.actual {
+                print_diff(actual, &failure.expected);
+            } else {
+                println!("    Timed out after {} microseconds", timeout_micros);
+            }
         }
-        Err(Error(String::new()))
+        Err(Error::TestFailure)
     } else {
         Ok(())
     }
@@ -128,7 +161,8 @@ fn run_tests(
     test_entry: TestEntry,
     filter: Option<&str>,
     mut indent_level: i32,
-    failures: &mut Vec<(String, String, String)>,
+    group: &str,
+    results: &mut Vec<TestResult>,
 ) -> Result<()> {
     match test_entry {
         TestEntry::Example {
@@ -141,17 +175,33 @@ fn run_tests(
                     return Ok(());
                 }
             }
-            let tree = parser.parse(&input, None).unwrap();
-            let actual = tree.root_node().to_sexp();
+            let start_time = Instant::now();
+            let tree = parser.parse(&input, None);
+            let duration = start_time.elapsed();
             for _ in 0..indent_level {
                 print!("  ");
             }
-            if actual == output {
-                println!("✓ {}", Colour::Green.paint(&name));
+            let actual = if let Some(tree) = tree {
+                let actual = tree.root_node().to_sexp();
+                if actual == output {
+                    println!("✓ {}", Colour::Green.paint(&name));
+                } else {
+                    println!("✗ {}", Colour::Red.paint(&name));
+                }
+                Some(actual)
             } else {
-                println!("✗ {}", Colour::Red.paint(&name));
-                failures.push((name, actual, output));
-            }
+                // Discard the partial parse so the next example starts from scratch.
+                parser.reset();
+                println!("✗ {} (timed out)", Colour::Red.paint(&name));
+                None
+            };
+            results.push(TestResult {
+                group: group.to_string(),
+                name,
+                duration,
+                expected: output,
+                actual,
+            });
         }
         TestEntry::Group { name, children } => {
             for _ in 0..indent_level {
@@ -159,14 +209,85 @@ fn run_tests(
             }
             println!("{}:", name);
             indent_level += 1;
+            let group = if group.is_empty() {
+                name
+            } else {
+                format!("{}.{}", group, name)
+            };
             for child in children {
-                run_tests(parser, child, filter, indent_level, failures)?;
+                run_tests(parser, child, filter, indent_level, &group, results)?;
             }
         }
     }
     Ok(())
 }
 
+fn junit_xml(results: &[TestResult], timeout_micros: u64) -> String {
+    let failure_count = results.iter().filter(|result| !result.passed()).count();
+    let total_duration = results
+        .iter()
+        .fold(Duration::new(0, 0), |total, result| total + result.duration);
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuite name=\"tree-sitter\" tests=\"{}\" failures=\"{}\" time=\"{}\">\n",
+        results.len(),
+        failure_count,
+        junit_seconds(total_duration)
+    ));
+    for result in results {
+        xml.push_str(&format!(
+            "  <testcase classname=\"{}\" name=\"{}\" time=\"{}\"",
+            escape_xml(&result.group),
+            escape_xml(&result.name),
+            junit_seconds(result.duration)
+        ));
+        if result.passed() {
+            xml.push_str("/>\n");
+            continue;
+        }
+        xml.push_str(">\n");
+        if let Some(actual) = &result.actual {
+            xml.push_str(&format!(
+                "    <failure message=\"Unexpected syntax tree\">expected: {}\nactual: {}</failure>\n",
+                escape_xml(&result.expected),
+                escape_xml(actual)
+            ));
+        } else {
+            xml.push_str(&format!(
+                "    <failure message=\"Timed out after {} microseconds\"/>\n",
+                timeout_micros
+            ));
+        }
+        xml.push_str("  </testcase>\n");
+    }
+    xml.push_str("</testsuite>\n");
+    xml
+}
+
+fn junit_seconds(duration: Duration) -> String {
+    format!(
+        "{:.3}",
+        duration.as_secs() as f64 + duration.subsec_nanos() as f64 / 1e9
+    )
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
 pub fn parse_tests(path: &Path) -> io::Result<TestEntry> {
     let name = path
         .file_stem()
@@ -275,4 +396,47 @@ d
             }
         );
     }
+
+    #[test]
+    fn test_junit_xml() {
+        let results = vec![
+            TestResult {
+                group: "literals".to_string(),
+                name: "Numbers".to_string(),
+                duration: Duration::from_millis(2),
+                expected: "(number)".to_string(),
+                actual: Some("(number)".to_string()),
+            },
+            TestResult {
+                group: "literals".to_string(),
+                name: "Strings & <escapes>".to_string(),
+                duration: Duration::from_millis(1),
+                expected: "(string)".to_string(),
+                actual: Some("(ERROR)".to_string()),
+            },
+            TestResult {
+                group: "literals".to_string(),
+                name: "Runaway".to_string(),
+                duration: Duration::from_millis(5),
+                expected: "(array)".to_string(),
+                actual: None,
+            },
+        ];
+
+        assert_eq!(
+            junit_xml(&results, 1000),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuite name="tree-sitter" tests="3" failures="2" time="0.008">
+  <testcase classname="literals" name="Numbers" time="0.002"/>
+  <testcase classname="literals" name="Strings &amp; &lt;escapes&gt;" time="0.001">
+    <failure message="Unexpected syntax tree">expected: (string)
+actual: (ERROR)</failure>
+  </testcase>
+  <testcase classname="literals" name="Runaway" time="0.005">
+    <failure message="Timed out after 1000 microseconds"/>
+  </testcase>
+</testsuite>
+"#
+        );
+    }
 }
@@ -1,5 +1,5 @@
 pub(super) mod allocations;
-pub(super) mod fixtures;
+pub(crate) mod fixtures;
 pub(super) mod random;
 pub(super) mod scope_sequence;
 pub(super) mod edits;
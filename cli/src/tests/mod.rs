@@ -1,6 +1,7 @@
 mod corpus_test;
-mod helpers;
+pub(crate) mod helpers;
 mod node_test;
+mod parse_test;
 mod parser_test;
 mod properties_test;
 mod tree_test;
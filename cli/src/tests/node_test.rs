@@ -259,6 +259,25 @@ fn test_node_named_child_with_aliases_and_extras() {
     assert_eq!(root.named_child(4).unwrap().kind(), "C");
 }
 
+#[test]
+fn test_node_is_extra() {
+    let (parser_name, parser_code) =
+        generate_parser_for_grammar(GRAMMAR_WITH_ALIASES_AND_EXTRAS).unwrap();
+
+    let mut parser = Parser::new();
+    parser
+        .set_language(get_test_language(&parser_name, &parser_code, None))
+        .unwrap();
+
+    let tree = parser.parse("b ... b c", None).unwrap();
+    let root = tree.root_node();
+    assert!(!root.is_extra());
+    assert!(!root.named_child(0).unwrap().is_extra());
+    assert!(root.named_child(1).unwrap().is_extra());
+    assert!(!root.named_child(2).unwrap().is_extra());
+    assert!(!root.named_child(3).unwrap().is_extra());
+}
+
 #[test]
 fn test_node_descendant_for_range() {
     let tree = parse_json_example();
@@ -0,0 +1,141 @@
+use super::helpers::fixtures::{get_language, get_test_language, scratch_dir};
+use crate::generate::generate_parser_for_grammar;
+use crate::parse::{
+    parse_file_at_path, print_symbol_usage, print_tree, ParseOptions, MMAP_THRESHOLD,
+};
+use std::fs;
+use tree_sitter::Parser;
+
+#[test]
+fn test_parsing_a_file_with_a_timeout() {
+    let mut source_code = "[".to_string();
+    for i in 0..100000 {
+        source_code += &format!("{}, ", i);
+    }
+    source_code += "0]";
+    let path = scratch_dir().join("large-array.json");
+    fs::write(&path, &source_code).unwrap();
+
+    let parse = |timeout_micros| {
+        let options = ParseOptions {
+            quiet: true,
+            timeout_micros,
+            ..ParseOptions::default()
+        };
+        parse_file_at_path(get_language("json"), &path, 0, &options).unwrap()
+    };
+
+    // The file cannot be parsed within a microsecond, so it is reported as an error.
+    assert!(parse(1));
+
+    // Without a timeout, the file parses cleanly.
+    assert!(!parse(0));
+}
+
+#[test]
+fn test_parsing_a_file_with_a_byte_order_mark() {
+    let path = scratch_dir().join("bom.json");
+    fs::write(&path, b"\xEF\xBB\xBF{\"a\": [1, 2]}").unwrap();
+
+    let options = ParseOptions {
+        quiet: true,
+        ..ParseOptions::default()
+    };
+    let has_error = parse_file_at_path(get_language("json"), &path, 0, &options).unwrap();
+    assert!(!has_error);
+}
+
+#[test]
+fn test_parsing_a_memory_mapped_file() {
+    let mut source_code = "\u{FEFF}[".to_string();
+    while (source_code.len() as u64) < MMAP_THRESHOLD {
+        source_code += "1234567, ";
+    }
+    source_code += "0]";
+    let path = scratch_dir().join("mapped.json");
+    fs::write(&path, &source_code).unwrap();
+
+    let options = ParseOptions {
+        quiet: true,
+        ..ParseOptions::default()
+    };
+    let has_error = parse_file_at_path(get_language("json"), &path, 0, &options).unwrap();
+    assert!(!has_error);
+}
+
+#[test]
+fn test_parsing_a_file_repeatedly() {
+    let path = scratch_dir().join("repeat.json");
+    fs::write(&path, "{\"a\": [1, {\"b\": null}], \"c\": \"d\"}\n").unwrap();
+
+    let options = ParseOptions {
+        quiet: true,
+        repeat: 3,
+        ..ParseOptions::default()
+    };
+    let has_error = parse_file_at_path(get_language("json"), &path, 0, &options).unwrap();
+    assert!(!has_error);
+}
+
+#[test]
+fn test_printing_a_tree() {
+    let mut parser = Parser::new();
+    parser.set_language(get_language("json")).unwrap();
+    let tree = parser.parse("[1, 2]", None).unwrap();
+
+    let mut output = Vec::new();
+    print_tree(tree.root_node(), false, false, &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        concat!(
+            "(value [0, 0] - [0, 6]\n",
+            "  (array [0, 0] - [0, 6]\n",
+            "    (number [0, 1] - [0, 2])\n",
+            "    (number [0, 4] - [0, 5])))\n",
+        )
+    );
+
+    // The compact form prints the whole tree on a single line.
+    let mut output = Vec::new();
+    print_tree(tree.root_node(), true, false, &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "(value (array (number) (number)))\n"
+    );
+}
+
+#[test]
+fn test_printing_symbol_usage() {
+    let (parser_name, parser_code) = generate_parser_for_grammar(
+        r#"{
+            "name": "symbol_usage",
+            "rules": {
+                "program": {
+                    "type": "CHOICE",
+                    "members": [
+                        {"type": "SYMBOL", "name": "_item"},
+                        {"type": "SYMBOL", "name": "unused"}
+                    ]
+                },
+                "_item": {"type": "SYMBOL", "name": "word"},
+                "word": {"type": "PATTERN", "value": "[a-z]+"},
+                "unused": {"type": "STRING", "value": "1"}
+            }
+        }"#,
+    )
+    .unwrap();
+    let language = get_test_language(&parser_name, &parser_code, None);
+
+    let mut parser = Parser::new();
+    parser.set_language(language).unwrap();
+    let tree = parser.parse("abc", None).unwrap();
+
+    // Hidden rules and the end-of-input symbol never appear in a tree, so
+    // they are not counted.
+    let mut output = Vec::new();
+    print_symbol_usage(language, &tree, &mut output).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "used 2 of 3 symbols\n  unused: unused\n"
+    );
+}
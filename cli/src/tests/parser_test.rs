@@ -1,7 +1,7 @@
 use super::helpers::edits::{perform_edit, Edit, ReadRecorder};
 use super::helpers::fixtures::{get_language, get_test_language};
 use crate::generate::generate_parser_for_grammar;
-use std::{thread, usize};
+use std::{thread, time, usize};
 use tree_sitter::{InputEdit, LogType, Parser, Point, Range};
 
 #[test]
@@ -350,6 +350,48 @@ fn test_parsing_with_a_reset_after_reaching_an_operation_limit() {
     );
 }
 
+// Timeouts
+
+#[test]
+fn test_parsing_with_a_timeout() {
+    let mut parser = Parser::new();
+    parser.set_language(get_language("json")).unwrap();
+
+    // Start parsing from an infinite input. Parsing should abort after 1 millisecond.
+    parser.set_timeout_micros(1000);
+    assert_eq!(parser.timeout_micros(), 1000);
+    let start_time = time::Instant::now();
+    let tree = parser.parse_with(
+        &mut |offset, _| if offset == 0 { b"[" } else { b",0" },
+        None,
+    );
+    assert!(tree.is_none());
+    assert!(start_time.elapsed() < time::Duration::from_millis(100));
+
+    // With the timeout disabled and the parser reset, parsing completes normally.
+    parser.set_timeout_micros(0);
+    parser.reset();
+    let tree = parser.parse("[0, 1]", None).unwrap();
+    assert_eq!(
+        tree.root_node().to_sexp(),
+        "(value (array (number) (number)))"
+    );
+}
+
+#[test]
+fn test_parsing_with_a_very_large_timeout() {
+    let mut parser = Parser::new();
+    parser.set_language(get_language("json")).unwrap();
+
+    // A timeout too large to represent in clock ticks behaves like no timeout at all.
+    parser.set_timeout_micros(u64::MAX);
+    let tree = parser.parse("[0, 1]", None).unwrap();
+    assert_eq!(
+        tree.root_node().to_sexp(),
+        "(value (array (number) (number)))"
+    );
+}
+
 // Included Ranges
 
 #[test]
@@ -148,6 +148,12 @@ extern "C" {
 extern "C" {
     pub fn ts_parser_set_operation_limit(arg1: *mut TSParser, arg2: usize);
 }
+extern "C" {
+    pub fn ts_parser_timeout_micros(arg1: *const TSParser) -> u64;
+}
+extern "C" {
+    pub fn ts_parser_set_timeout_micros(arg1: *mut TSParser, arg2: u64);
+}
 extern "C" {
     pub fn ts_parser_reset(arg1: *mut TSParser);
 }
@@ -215,6 +221,9 @@ extern "C" {
 extern "C" {
     pub fn ts_node_is_missing(arg1: TSNode) -> bool;
 }
+extern "C" {
+    pub fn ts_node_is_extra(arg1: TSNode) -> bool;
+}
 extern "C" {
     pub fn ts_node_has_changes(arg1: TSNode) -> bool;
 }
@@ -316,6 +325,9 @@ extern "C" {
 extern "C" {
     pub fn ts_language_symbol_type(arg1: *const TSLanguage, arg2: TSSymbol) -> TSSymbolType;
 }
+extern "C" {
+    pub fn ts_language_symbol_is_visible(arg1: *const TSLanguage, arg2: TSSymbol) -> bool;
+}
 extern "C" {
     pub fn ts_language_version(arg1: *const TSLanguage) -> u32;
 }
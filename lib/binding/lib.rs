@@ -21,6 +21,7 @@ use std::slice;
 use std::str;
 use std::u16;
 
+pub const LANGUAGE_VERSION: usize = ffi::TREE_SITTER_LANGUAGE_VERSION;
 pub const PARSER_HEADER: &'static str = include_str!("../include/tree_sitter/parser.h");
 
 #[derive(Clone, Copy)]
@@ -140,6 +141,10 @@ impl Language {
     pub fn node_kind_is_named(&self, id: u16) -> bool {
         unsafe { ffi::ts_language_symbol_type(self.0, id) == ffi::TSSymbolType_TSSymbolTypeRegular }
     }
+
+    pub fn node_kind_is_visible(&self, id: u16) -> bool {
+        unsafe { ffi::ts_language_symbol_is_visible(self.0, id) }
+    }
 }
 
 unsafe impl Send for Language {}
@@ -321,6 +326,14 @@ impl Parser {
         unsafe { ffi::ts_parser_set_operation_limit(self.0, limit) }
     }
 
+    pub fn timeout_micros(&self) -> u64 {
+        unsafe { ffi::ts_parser_timeout_micros(self.0) }
+    }
+
+    pub fn set_timeout_micros(&mut self, timeout_micros: u64) {
+        unsafe { ffi::ts_parser_set_timeout_micros(self.0, timeout_micros) }
+    }
+
     pub fn set_included_ranges(&mut self, ranges: &[Range]) {
         let ts_ranges: Vec<ffi::TSRange> =
             ranges.iter().cloned().map(|range| range.into()).collect();
@@ -434,6 +447,10 @@ impl<'tree> Node<'tree> {
         unsafe { ffi::ts_node_is_missing(self.0) }
     }
 
+    pub fn is_extra(&self) -> bool {
+        unsafe { ffi::ts_node_is_extra(self.0) }
+    }
+
     pub fn start_byte(&self) -> usize {
         unsafe { ffi::ts_node_start_byte(self.0) as usize }
     }
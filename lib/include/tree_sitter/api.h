@@ -92,6 +92,9 @@ bool ts_parser_enabled(const TSParser *);
 void ts_parser_set_enabled(TSParser *, bool);
 size_t ts_parser_operation_limit(const TSParser *);
 void ts_parser_set_operation_limit(TSParser *, size_t);
+// The timeout measures processor time as reported by `clock()`, not wall-clock time.
+uint64_t ts_parser_timeout_micros(const TSParser *);
+void ts_parser_set_timeout_micros(TSParser *, uint64_t);
 void ts_parser_reset(TSParser *);
 void ts_parser_set_included_ranges(TSParser *, const TSRange *, uint32_t);
 const TSRange *ts_parser_included_ranges(const TSParser *, uint32_t *);
@@ -115,6 +118,7 @@ bool ts_node_eq(TSNode, TSNode);
 bool ts_node_is_null(TSNode);
 bool ts_node_is_named(TSNode);
 bool ts_node_is_missing(TSNode);
+bool ts_node_is_extra(TSNode);
 bool ts_node_has_changes(TSNode);
 bool ts_node_has_error(TSNode);
 TSNode ts_node_parent(TSNode);
@@ -147,6 +151,7 @@ uint32_t ts_language_symbol_count(const TSLanguage *);
 const char *ts_language_symbol_name(const TSLanguage *, TSSymbol);
 TSSymbol ts_language_symbol_for_name(const TSLanguage *, const char *);
 TSSymbolType ts_language_symbol_type(const TSLanguage *, TSSymbol);
+bool ts_language_symbol_is_visible(const TSLanguage *, TSSymbol);
 uint32_t ts_language_version(const TSLanguage *);
 
 #ifdef __cplusplus
@@ -59,6 +59,10 @@ TSSymbol ts_language_symbol_for_name(const TSLanguage *self, const char *name) {
   return 0;
 }
 
+bool ts_language_symbol_is_visible(const TSLanguage *language, TSSymbol symbol) {
+  return ts_language_symbol_metadata(language, symbol).visible;
+}
+
 TSSymbolType ts_language_symbol_type(const TSLanguage *language, TSSymbol symbol) {
   TSSymbolMetadata metadata = ts_language_symbol_metadata(language, symbol);
   if (metadata.named) {
@@ -402,6 +402,10 @@ bool ts_node_is_missing(TSNode self) {
   return ts_subtree_missing(ts_node__subtree(self));
 }
 
+bool ts_node_is_extra(TSNode self) {
+  return ts_subtree_extra(ts_node__subtree(self));
+}
+
 bool ts_node_has_changes(TSNode self) {
   return ts_subtree_has_changes(ts_node__subtree(self));
 }
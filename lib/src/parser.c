@@ -2,6 +2,7 @@
 #include <stdio.h>
 #include <limits.h>
 #include <stdbool.h>
+#include <time.h>
 #include "tree_sitter/api.h"
 #include "./subtree.h"
 #include "./lexer.h"
@@ -42,6 +43,8 @@ static const unsigned MAX_VERSION_COUNT = 6;
 static const unsigned MAX_VERSION_COUNT_OVERFLOW = 4;
 static const unsigned MAX_SUMMARY_DEPTH = 16;
 static const unsigned MAX_COST_DIFFERENCE = 16 * ERROR_COST_PER_SKIPPED_TREE;
+static const unsigned OP_COUNT_PER_TIMEOUT_CHECK = 100;
+static const uint64_t MAX_CLOCK = ((uint64_t)1 << (sizeof(clock_t) * CHAR_BIT - 1)) - 1;
 
 typedef struct {
   Subtree token;
@@ -64,6 +67,7 @@ struct TSParser {
   FILE *dot_graph_file;
   unsigned accept_count;
   size_t operation_limit;
+  uint64_t timeout_micros;
   volatile bool enabled;
   bool halt_on_error;
   Subtree old_tree;
@@ -1493,6 +1497,7 @@ TSParser *ts_parser_new() {
   self->halt_on_error = false;
   self->enabled = true;
   self->operation_limit = SIZE_MAX;
+  self->timeout_micros = 0;
   self->old_tree = NULL_SUBTREE;
   self->scratch_tree.ptr = &self->scratch_tree_data;
   self->included_range_differences = (TSRangeArray) array_new();
@@ -1582,6 +1587,14 @@ void ts_parser_set_operation_limit(TSParser *self, size_t limit) {
   self->operation_limit = limit;
 }
 
+uint64_t ts_parser_timeout_micros(const TSParser *self) {
+  return self->timeout_micros;
+}
+
+void ts_parser_set_timeout_micros(TSParser *self, uint64_t timeout_micros) {
+  self->timeout_micros = timeout_micros;
+}
+
 void ts_parser_set_included_ranges(TSParser *self, const TSRange *ranges, uint32_t count) {
   ts_lexer_set_included_ranges(&self->lexer, ranges, count);
 }
@@ -1643,6 +1656,25 @@ TSTree *ts_parser_parse(TSParser *self, const TSTree *old_tree, TSInput input) {
 
   uint32_t position = 0, last_position = 0, version_count = 0;
   size_t operation_count = 0;
+  clock_t end_clock = 0;
+  if (self->timeout_micros) {
+    // Round up, so that a timeout shorter than one clock tick still takes effect
+    // on platforms where `CLOCKS_PER_SEC` is less than one million.
+    // The whole seconds and the remainder are scaled separately so that large
+    // timeouts don't overflow, and the sum saturates rather than wrapping `clock_t`.
+    uint64_t timeout_seconds = self->timeout_micros / 1000000;
+    uint64_t timeout_ticks = (self->timeout_micros % 1000000 * CLOCKS_PER_SEC + 999999) / 1000000;
+    uint64_t start_clock = (uint64_t)clock();
+    uint64_t max_ticks = start_clock < MAX_CLOCK ? MAX_CLOCK - start_clock : 0;
+    if (
+      timeout_ticks > max_ticks ||
+      timeout_seconds > (max_ticks - timeout_ticks) / CLOCKS_PER_SEC
+    ) {
+      end_clock = (clock_t)MAX_CLOCK;
+    } else {
+      end_clock = (clock_t)(start_clock + timeout_seconds * CLOCKS_PER_SEC + timeout_ticks);
+    }
+  }
 
   do {
     for (StackVersion version = 0;
@@ -1650,6 +1682,11 @@ TSTree *ts_parser_parse(TSParser *self, const TSTree *old_tree, TSInput input) {
          version++) {
       if (operation_count > self->operation_limit || !self->enabled) return NULL;
       operation_count++;
+      if (
+        end_clock &&
+        operation_count % OP_COUNT_PER_TIMEOUT_CHECK == 0 &&
+        clock() > end_clock
+      ) return NULL;
 
       bool allow_node_reuse = version_count == 1;
       while (ts_stack_is_active(self->stack, version)) {